@@ -1,20 +1,145 @@
 use core::fmt::{self, Display};
-use core::num::NonZeroU16;
 use core::num::ParseIntError;
 use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
 use core::str::FromStr;
 
-/// Uses Stein's algorithm to calculate the gcd of two numbers
-const fn gcd(mut a: u16, mut b: u16) -> u16 {
-	// returns the other if one of the two numbers are zero
+/// Minimal integer operations a [`Fraction`]'s numerator and denominator
+/// must support. Implemented for `i16` (backing [`Fraction32`]) and for
+/// [`BigInt`] (backing [`FractionBig`]), so the arithmetic, `gcd`/`lcm`,
+/// `reduce`, and `reciprocal` logic on [`Fraction<T>`] only needs to be
+/// written once.
+///
+/// Methods take their operands by reference: unlike `i16`, [`BigInt`] is
+/// heap-backed and not `Copy`, so `Fraction<T>` only ever needs to clone a
+/// value when it actually has to keep two independent copies around.
+pub trait Int: Clone + Eq + Ord + fmt::Debug + Display {
+	fn zero() -> Self;
+	fn one() -> Self;
+
+	/// The largest denominator this type can represent, or `None` if it
+	/// has no meaningful upper bound (as with [`BigInt`]).
+	fn max_denominator() -> Option<Self>;
+
+	fn checked_add(&self, rhs: &Self) -> Option<Self>;
+	fn checked_sub(&self, rhs: &Self) -> Option<Self>;
+	fn checked_mul(&self, rhs: &Self) -> Option<Self>;
+	fn checked_div(&self, rhs: &Self) -> Option<Self>;
+	fn checked_rem(&self, rhs: &Self) -> Option<Self>;
+	fn checked_neg(&self) -> Option<Self>;
+
+	fn is_negative(&self) -> bool;
+	fn signum(&self) -> Self;
+
+	/// Orders `a_num/a_den` against `b_num/b_den` (both denominators
+	/// assumed positive) by cross-multiplying. Unlike going through
+	/// `checked_mul`, this must not spuriously fail on in-range fractions:
+	/// backends whose `checked_mul` can overflow (like `i16`) need to
+	/// cross-multiply in a wider representation, while backends that never
+	/// overflow (like `BigInt`) can cross-multiply directly.
+	fn cross_compare(
+		a_num: &Self,
+		a_den: &Self,
+		b_num: &Self,
+		b_den: &Self,
+	) -> core::cmp::Ordering;
+}
+
+impl Int for i16 {
+	fn zero() -> Self {
+		0
+	}
+
+	fn one() -> Self {
+		1
+	}
+
+	fn max_denominator() -> Option<Self> {
+		Some(i16::MAX)
+	}
+
+	fn checked_add(&self, rhs: &Self) -> Option<Self> {
+		i16::checked_add(*self, *rhs)
+	}
+
+	fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+		i16::checked_sub(*self, *rhs)
+	}
+
+	fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+		i16::checked_mul(*self, *rhs)
+	}
+
+	fn checked_div(&self, rhs: &Self) -> Option<Self> {
+		i16::checked_div(*self, *rhs)
+	}
+
+	fn checked_rem(&self, rhs: &Self) -> Option<Self> {
+		i16::checked_rem(*self, *rhs)
+	}
+
+	fn checked_neg(&self) -> Option<Self> {
+		i16::checked_neg(*self)
+	}
+
+	fn is_negative(&self) -> bool {
+		*self < 0
+	}
+
+	fn signum(&self) -> Self {
+		i16::signum(*self)
+	}
+
+	fn cross_compare(a_num: &Self, a_den: &Self, b_num: &Self, b_den: &Self) -> core::cmp::Ordering {
+		// i16 * i16 always fits in i64, so this can't overflow the way
+		// comparing via checked_mul on i16 directly can.
+		let lhs = i64::from(*a_num) * i64::from(*b_den);
+		let rhs = i64::from(*b_num) * i64::from(*a_den);
+		lhs.cmp(&rhs)
+	}
+}
+
+/// The absolute value of a generic [`Int`].
+fn abs<T: Int>(value: &T) -> T {
+	if value.is_negative() {
+		value
+			.checked_neg()
+			.expect("negating a valid fraction component should not overflow")
+	} else {
+		value.clone()
+	}
+}
+
+/// Euclid's algorithm, generic over any [`Int`] backend.
+fn gcd<T: Int>(a: &T, b: &T) -> T {
+	let mut a = a.clone();
+	let mut b = b.clone();
+	while b != T::zero() {
+		let r = a
+			.checked_rem(&b)
+			.expect("b is checked nonzero by the loop condition");
+		a = b;
+		b = r;
+	}
+	a
+}
+
+/// Returns `None` if the lcm doesn't fit back into `T`.
+fn lcm<T: Int>(a: &T, b: &T) -> Option<T> {
+	if *a == T::zero() || *b == T::zero() {
+		return Some(T::zero());
+	}
+	a.checked_div(&gcd(a, b))?.checked_mul(b)
+}
+
+/// Uses Stein's algorithm to calculate the gcd of two numbers, in a wider
+/// type so that products of two `u16`s can be reduced without truncating.
+const fn gcd_wide(mut a: u32, mut b: u32) -> u32 {
 	if a == 0 || b == 0 {
 		return a | b;
 	}
 
-	// find common factors of two
 	let shift = (a | b).trailing_zeros();
 
-	// divide both by two until they're odd
 	a >>= a.trailing_zeros();
 	b >>= b.trailing_zeros();
 
@@ -31,93 +156,758 @@ const fn gcd(mut a: u16, mut b: u16) -> u16 {
 	a << shift
 }
 
-const fn lcm(a: u16, b: u16) -> u16 {
-	let gcd = gcd(a, b);
-	a * b / gcd
+/// Computes the lcm of two denominators in a wider type, returning `None`
+/// on overflow rather than panicking.
+fn lcm_wide(a: u32, b: u32) -> Option<u32> {
+	let gcd = gcd_wide(a, b);
+	(a / gcd).checked_mul(b)
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct Fraction32 {
-	numerator: i16,
-	denominator: NonZeroU16,
+/// Euclid's algorithm over `u64`, for reducing values (like a parsed
+/// decimal literal's numerator/power-of-ten pair) computed in a type wider
+/// than `i16`, before they are narrowed into a `Fraction32`.
+fn gcd_u64(mut a: u64, mut b: u64) -> u64 {
+	while b != 0 {
+		let r = a % b;
+		a = b;
+		b = r;
+	}
+	a
 }
 
-impl Fraction32 {
-	pub const ZERO: Self = Self::whole(0);
-	pub const ONE: Self = Self::whole(1);
-	pub const NEG_ONE: Self = Self::whole(-1);
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fraction<T: Int> {
+	numerator: T,
+	denominator: T,
+}
 
-	/// Create a new fraction
-	///
-	/// # Panics
-	///
-	/// This panics if the denominator is larger than `i16::MAX`
-	#[must_use]
-	pub fn new(numerator: i16, denominator: NonZeroU16) -> Self {
-		let this = Self {
-			numerator,
-			denominator,
-		};
+/// `Fraction<T>` is `Copy` whenever its backend is, i.e. for `Fraction32`
+/// but not `FractionBig`.
+impl<T: Int + Copy> Copy for Fraction<T> {}
+
+/// The fixed-width fraction this crate started with, now an alias for
+/// `Fraction<i16>`.
+pub type Fraction32 = Fraction<i16>;
 
-		// check for a denominator that's too large
-		assert!(denominator.get() <= i16::MAX.unsigned_abs());
+/// A fraction backed by [`BigInt`], for simplex runs whose numerators and
+/// denominators outgrow what `Fraction32` can hold.
+pub type FractionBig = Fraction<BigInt>;
 
-		// simplify the fraction
-		this.reduce()
+impl<T: Int> Fraction<T> {
+	#[must_use]
+	pub fn zero() -> Self {
+		Self {
+			numerator: T::zero(),
+			denominator: T::one(),
+		}
 	}
 
-	/// Create a fraction from a whole number
 	#[must_use]
-	pub const fn whole(num: i16) -> Self {
-		// safety: one is neither zero, nor greater than 35,000
-		unsafe { Self::new_unchecked(num, 1) }
+	pub fn one() -> Self {
+		Self {
+			numerator: T::one(),
+			denominator: T::one(),
+		}
 	}
 
 	/// Create a new fraction
 	///
-	/// # Safety
+	/// # Panics
 	///
-	/// The `denominator` cannot be zero, or larger than `i16::MAX`
+	/// This panics if `denominator` isn't positive, or if it's larger than
+	/// `T::max_denominator()` (for backends that have one, like `i16`).
 	#[must_use]
-	pub const unsafe fn new_unchecked(numerator: i16, denominator: u16) -> Self {
+	pub fn new(numerator: T, denominator: T) -> Self {
+		assert!(denominator > T::zero(), "denominator must be positive");
+		if let Some(max) = T::max_denominator() {
+			assert!(
+				denominator <= max,
+				"denominator is too large to be represented"
+			);
+		}
+
 		Self {
 			numerator,
-			denominator: NonZeroU16::new_unchecked(denominator),
+			denominator,
 		}
+		.reduce()
 	}
 
+	/// Create a fraction from a whole number
 	#[must_use]
-	pub const fn numerator(self) -> i16 {
-		self.numerator
+	pub fn whole(num: T) -> Self {
+		Self::new(num, T::one())
 	}
 
 	#[must_use]
-	pub const fn denominator(self) -> NonZeroU16 {
-		self.denominator
+	pub fn numerator(&self) -> T {
+		self.numerator.clone()
+	}
+
+	#[must_use]
+	pub fn denominator(&self) -> T {
+		self.denominator.clone()
 	}
 
 	/// Simplify the fraction
 	#[must_use]
-	#[allow(clippy::missing_panics_doc)]
 	pub fn reduce(self) -> Self {
-		if self.numerator == 0 {
-			return Self::ZERO;
+		if self.numerator == T::zero() {
+			return Self::zero();
 		}
 
-		let gcd = gcd(self.numerator.unsigned_abs(), self.denominator.get());
-		let numerator = self.numerator / i16::try_from(gcd).unwrap();
-		let denominator = self.denominator.get() / gcd;
+		let gcd = gcd(&abs(&self.numerator), &self.denominator);
 
-		Self::new(numerator, denominator.try_into().unwrap())
+		Self {
+			numerator: self
+				.numerator
+				.checked_div(&gcd)
+				.expect("the gcd of the numerator and denominator divides the numerator evenly"),
+			denominator: self
+				.denominator
+				.checked_div(&gcd)
+				.expect("the gcd of the numerator and denominator divides the denominator evenly"),
+		}
 	}
 
 	/// Returns the reciprocal of the fraction.
 	/// Returns `None` if the numerator is currently zero.
 	#[must_use]
-	#[allow(clippy::missing_panics_doc)]
 	pub fn reciprocal(self) -> Option<Self> {
-		let numerator = i16::try_from(self.denominator.get()).unwrap() * self.numerator.signum();
-		let denominator = self.numerator.unsigned_abs().try_into().ok()?;
+		if self.numerator == T::zero() {
+			return None;
+		}
+
+		let numerator = self.denominator.checked_mul(&self.numerator.signum())?;
+		let denominator = abs(&self.numerator);
+
+		Some(Self::new(numerator, denominator))
+	}
+}
+
+/// A true arbitrary-precision integer backend for [`Fraction`], for simplex
+/// runs whose numerators and denominators outgrow `i16`. Stored as a sign
+/// plus a little-endian `Vec` of base-2^32 limbs, so (unlike a fixed-width
+/// wrapper) it never overflows: the only operation that can fail is
+/// division/remainder by zero.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BigInt {
+	negative: bool,
+	magnitude: Vec<u32>,
+}
+
+impl BigInt {
+	#[must_use]
+	pub fn new(value: i128) -> Self {
+		Self::from(value)
+	}
+
+	/// Builds a `BigInt` from a sign and magnitude, normalizing `-0` to `0`.
+	fn from_sign_magnitude(negative: bool, magnitude: Vec<u32>) -> Self {
+		if magnitude.is_empty() {
+			Self {
+				negative: false,
+				magnitude,
+			}
+		} else {
+			Self { negative, magnitude }
+		}
+	}
+}
+
+impl From<i128> for BigInt {
+	fn from(value: i128) -> Self {
+		let negative = value < 0;
+		let mut remaining = value.unsigned_abs();
+		let mut magnitude = Vec::new();
+		while remaining != 0 {
+			magnitude.push((remaining & 0xffff_ffff) as u32);
+			remaining >>= 32;
+		}
+
+		Self::from_sign_magnitude(negative, magnitude)
+	}
+}
+
+impl Display for BigInt {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if self.magnitude.is_empty() {
+			return write!(f, "0");
+		}
+		if self.negative {
+			write!(f, "-")?;
+		}
+
+		// Peel off decimal digits by repeatedly dividing the magnitude by
+		// 10; the digits come out least-significant first.
+		let mut digits = Vec::new();
+		let mut remaining = self.magnitude.clone();
+		while !remaining.is_empty() {
+			let (quotient, digit) = divmod_small(&remaining, 10);
+			digits.push(b'0' + u8::try_from(digit).expect("remainder of dividing by 10 is < 10"));
+			remaining = quotient;
+		}
+
+		for &digit in digits.iter().rev() {
+			write!(f, "{}", digit as char)?;
+		}
+		Ok(())
+	}
+}
+
+impl PartialOrd for BigInt {
+	fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+impl Ord for BigInt {
+	fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+		match (self.negative, other.negative) {
+			(false, false) => cmp_magnitude(&self.magnitude, &other.magnitude),
+			(true, true) => cmp_magnitude(&other.magnitude, &self.magnitude),
+			(true, false) => core::cmp::Ordering::Less,
+			(false, true) => core::cmp::Ordering::Greater,
+		}
+	}
+}
+
+impl Int for BigInt {
+	fn zero() -> Self {
+		Self {
+			negative: false,
+			magnitude: Vec::new(),
+		}
+	}
+
+	fn one() -> Self {
+		Self {
+			negative: false,
+			magnitude: vec![1],
+		}
+	}
+
+	fn max_denominator() -> Option<Self> {
+		None
+	}
+
+	fn checked_add(&self, rhs: &Self) -> Option<Self> {
+		Some(if self.negative == rhs.negative {
+			Self::from_sign_magnitude(self.negative, add_magnitude(&self.magnitude, &rhs.magnitude))
+		} else if cmp_magnitude(&self.magnitude, &rhs.magnitude) == core::cmp::Ordering::Less {
+			Self::from_sign_magnitude(rhs.negative, sub_magnitude(&rhs.magnitude, &self.magnitude))
+		} else {
+			Self::from_sign_magnitude(self.negative, sub_magnitude(&self.magnitude, &rhs.magnitude))
+		})
+	}
+
+	fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+		self.checked_add(&rhs.checked_neg()?)
+	}
+
+	fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+		Some(Self::from_sign_magnitude(
+			self.negative != rhs.negative,
+			mul_magnitude(&self.magnitude, &rhs.magnitude),
+		))
+	}
+
+	fn checked_div(&self, rhs: &Self) -> Option<Self> {
+		if rhs.magnitude.is_empty() {
+			return None;
+		}
+		let (quotient, _) = divmod_magnitude(&self.magnitude, &rhs.magnitude);
+		Some(Self::from_sign_magnitude(
+			self.negative != rhs.negative,
+			quotient,
+		))
+	}
+
+	fn checked_rem(&self, rhs: &Self) -> Option<Self> {
+		if rhs.magnitude.is_empty() {
+			return None;
+		}
+		let (_, remainder) = divmod_magnitude(&self.magnitude, &rhs.magnitude);
+		// truncating division: the remainder takes the sign of `self`,
+		// matching `i16::checked_rem`
+		Some(Self::from_sign_magnitude(self.negative, remainder))
+	}
+
+	fn checked_neg(&self) -> Option<Self> {
+		Some(Self::from_sign_magnitude(
+			!self.negative,
+			self.magnitude.clone(),
+		))
+	}
+
+	fn is_negative(&self) -> bool {
+		self.negative
+	}
+
+	fn signum(&self) -> Self {
+		if self.magnitude.is_empty() {
+			Self::zero()
+		} else {
+			Self::from_sign_magnitude(self.negative, vec![1])
+		}
+	}
+
+	fn cross_compare(a_num: &Self, a_den: &Self, b_num: &Self, b_den: &Self) -> core::cmp::Ordering {
+		// BigInt multiplication never overflows, so there's no need to
+		// widen anything here.
+		let lhs = a_num
+			.checked_mul(b_den)
+			.expect("BigInt multiplication never overflows");
+		let rhs = b_num
+			.checked_mul(a_den)
+			.expect("BigInt multiplication never overflows");
+		lhs.cmp(&rhs)
+	}
+}
+
+/// Compares two normalized (no trailing zero limbs) little-endian magnitudes.
+fn cmp_magnitude(a: &[u32], b: &[u32]) -> core::cmp::Ordering {
+	if a.len() != b.len() {
+		return a.len().cmp(&b.len());
+	}
+	for i in (0..a.len()).rev() {
+		if a[i] != b[i] {
+			return a[i].cmp(&b[i]);
+		}
+	}
+	core::cmp::Ordering::Equal
+}
+
+/// Strips high zero limbs so magnitudes compare by length alone.
+fn normalize_magnitude(mut v: Vec<u32>) -> Vec<u32> {
+	while v.last() == Some(&0) {
+		v.pop();
+	}
+	v
+}
+
+fn add_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+	let mut result = Vec::with_capacity(a.len().max(b.len()) + 1);
+	let mut carry = 0u64;
+	for i in 0..a.len().max(b.len()) {
+		let sum = carry
+			+ u64::from(a.get(i).copied().unwrap_or(0))
+			+ u64::from(b.get(i).copied().unwrap_or(0));
+		result.push((sum & 0xffff_ffff) as u32);
+		carry = sum >> 32;
+	}
+	if carry != 0 {
+		result.push(u32::try_from(carry).expect("a carry out of one limb fits in one limb"));
+	}
+	normalize_magnitude(result)
+}
+
+/// Subtracts `b` from `a`, assuming `a >= b`.
+fn sub_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+	let mut result = Vec::with_capacity(a.len());
+	let mut borrow = 0i64;
+	for (i, &ai) in a.iter().enumerate() {
+		let diff = i64::from(ai) - i64::from(b.get(i).copied().unwrap_or(0)) - borrow;
+		if diff < 0 {
+			result.push((diff + (1i64 << 32)) as u32);
+			borrow = 1;
+		} else {
+			result.push(diff as u32);
+			borrow = 0;
+		}
+	}
+	normalize_magnitude(result)
+}
+
+fn mul_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+	if a.is_empty() || b.is_empty() {
+		return Vec::new();
+	}
+
+	let mut result = vec![0u32; a.len() + b.len()];
+	for (i, &ai) in a.iter().enumerate() {
+		let mut carry = 0u64;
+		for (j, &bj) in b.iter().enumerate() {
+			let sum = u64::from(result[i + j]) + u64::from(ai) * u64::from(bj) + carry;
+			result[i + j] = (sum & 0xffff_ffff) as u32;
+			carry = sum >> 32;
+		}
+		let mut idx = i + b.len();
+		while carry != 0 {
+			let sum = u64::from(result[idx]) + carry;
+			result[idx] = (sum & 0xffff_ffff) as u32;
+			carry = sum >> 32;
+			idx += 1;
+		}
+	}
+	normalize_magnitude(result)
+}
+
+fn get_bit(v: &[u32], i: usize) -> bool {
+	let limb = i / 32;
+	let bit = i % 32;
+	v.get(limb).is_some_and(|l| (l >> bit) & 1 == 1)
+}
+
+fn set_bit(v: &mut Vec<u32>, i: usize) {
+	let limb = i / 32;
+	let bit = i % 32;
+	if v.len() <= limb {
+		v.resize(limb + 1, 0);
+	}
+	v[limb] |= 1 << bit;
+}
+
+fn shl1(v: &mut Vec<u32>) {
+	let mut carry = 0u32;
+	for limb in v.iter_mut() {
+		let new_carry = *limb >> 31;
+		*limb = (*limb << 1) | carry;
+		carry = new_carry;
+	}
+	if carry != 0 {
+		v.push(carry);
+	}
+}
+
+/// Schoolbook binary long division: walks `a`'s bits from most to least
+/// significant, building up the remainder one bit at a time and recording a
+/// quotient bit whenever the remainder is big enough to subtract `b` from.
+/// Assumes `b` is nonzero.
+fn divmod_magnitude(a: &[u32], b: &[u32]) -> (Vec<u32>, Vec<u32>) {
+	if cmp_magnitude(a, b) == core::cmp::Ordering::Less {
+		return (Vec::new(), a.to_vec());
+	}
+
+	let mut quotient = vec![0u32; a.len()];
+	let mut remainder = Vec::new();
+
+	for bit in (0..a.len() * 32).rev() {
+		shl1(&mut remainder);
+		if get_bit(a, bit) {
+			set_bit(&mut remainder, 0);
+		}
+		if cmp_magnitude(&remainder, b) != core::cmp::Ordering::Less {
+			remainder = sub_magnitude(&remainder, b);
+			set_bit(&mut quotient, bit);
+		}
+	}
+
+	(normalize_magnitude(quotient), remainder)
+}
+
+/// Divides a magnitude by a single limb, for converting to decimal.
+fn divmod_small(a: &[u32], divisor: u32) -> (Vec<u32>, u32) {
+	let mut quotient = vec![0u32; a.len()];
+	let mut remainder = 0u64;
+	for i in (0..a.len()).rev() {
+		let acc = (remainder << 32) | u64::from(a[i]);
+		quotient[i] = (acc / u64::from(divisor)) as u32;
+		remainder = acc % u64::from(divisor);
+	}
+	(normalize_magnitude(quotient), remainder as u32)
+}
+
+impl Fraction32 {
+	pub const NEG_ONE: Self = Self {
+		numerator: -1,
+		denominator: 1,
+	};
+
+	/// Reduces a numerator/denominator pair computed in a wider type back
+	/// down to `Fraction32`, returning `None` if the reduced value doesn't
+	/// fit back into `i16`.
+	fn from_wide(numerator: i32, denominator: u32) -> Option<Self> {
+		if numerator == 0 {
+			return Some(Self::zero());
+		}
+
+		let gcd = gcd_wide(numerator.unsigned_abs(), denominator);
+		let numerator = numerator / i32::try_from(gcd).ok()?;
+		let denominator = denominator / gcd;
+
+		let numerator = i16::try_from(numerator).ok()?;
+		let denominator = i16::try_from(denominator).ok()?;
+		if denominator == 0 {
+			return None;
+		}
+
+		Some(Self::new(numerator, denominator))
+	}
+
+	/// Checked addition. Computes the lcm of the two denominators in a
+	/// wider type to avoid overflowing during cross-scaling, returning
+	/// `None` if the reduced result doesn't fit back into a `Fraction32`.
+	#[must_use]
+	pub fn checked_add(self, rhs: Self) -> Option<Self> {
+		let lhs_denominator = u32::from(self.denominator.unsigned_abs());
+		let rhs_denominator = u32::from(rhs.denominator.unsigned_abs());
+		let denominator = lcm_wide(lhs_denominator, rhs_denominator)?;
+
+		let lhs_scale = i32::try_from(denominator / lhs_denominator).ok()?;
+		let rhs_scale = i32::try_from(denominator / rhs_denominator).ok()?;
+
+		let numerator = i32::from(self.numerator)
+			.checked_mul(lhs_scale)?
+			.checked_add(i32::from(rhs.numerator).checked_mul(rhs_scale)?)?;
+
+		Self::from_wide(numerator, denominator)
+	}
+
+	/// Checked subtraction. See [`Self::checked_add`].
+	#[must_use]
+	pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+		self.checked_add(rhs.checked_mul(Self::NEG_ONE)?)
+	}
+
+	/// Checked multiplication. Computes both the numerator product and the
+	/// denominator product in a wider type, returning `None` if the
+	/// reduced result doesn't fit back into a `Fraction32`.
+	#[must_use]
+	pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+		let numerator = i32::from(self.numerator).checked_mul(i32::from(rhs.numerator))?;
+		let denominator = u32::from(self.denominator.unsigned_abs())
+			.checked_mul(u32::from(rhs.denominator.unsigned_abs()))?;
+
+		Self::from_wide(numerator, denominator)
+	}
+
+	/// Checked division. Returns `None` if `rhs` is zero or if the
+	/// reduced result doesn't fit back into a `Fraction32`.
+	#[must_use]
+	pub fn checked_div(self, rhs: Self) -> Option<Self> {
+		self.checked_mul(rhs.checked_reciprocal()?)
+	}
+
+	/// Checked reciprocal. Returns `None` if the numerator is currently
+	/// zero, or if the reduced result doesn't fit back into a
+	/// `Fraction32`.
+	#[must_use]
+	pub fn checked_reciprocal(self) -> Option<Self> {
+		if self.numerator == 0 {
+			return None;
+		}
+
+		let numerator =
+			i32::from(self.denominator).checked_mul(i32::from(self.numerator.signum()))?;
+		let denominator = u32::from(self.numerator.unsigned_abs());
+
+		Self::from_wide(numerator, denominator)
+	}
+
+	/// Builds a fraction from a sign and an unreduced numerator/denominator
+	/// pair produced by a continued-fraction search.
+	///
+	/// # Panics
+	///
+	/// Panics if the numerator or denominator doesn't fit back into `i16`.
+	fn from_signed_parts(sign: i64, numerator: i64, denominator: i64) -> Self {
+		let numerator =
+			i16::try_from(sign * numerator).expect("convergent numerator should fit in i16");
+		let denominator =
+			i16::try_from(denominator).expect("convergent denominator should fit in i16");
+
+		Self::new(numerator, denominator)
+	}
+
+	/// Finds the best rational approximation of `value` whose denominator
+	/// does not exceed `max_denominator`.
+	///
+	/// This is needed because pivoting can produce fractions whose exact
+	/// reduced form overflows `i16`; approximating within a denominator
+	/// budget keeps the tableau representable.
+	///
+	/// Uses the Stern-Brocot / continued-fraction search: starting from the
+	/// bounding fractions `0/1` and `1/0`, each continued-fraction term
+	/// advances the mediant by a whole run of steps at once (rather than
+	/// one mediant at a time), keeping the search logarithmic in
+	/// `max_denominator`.
+	///
+	/// If `value` is exactly representable within the denominator budget,
+	/// it is returned unchanged (up to `f64` rounding). The sign of `value`
+	/// is preserved by approximating its absolute value and reattaching
+	/// the sign afterwards.
+	///
+	/// # Panics
+	///
+	/// Panics if `max_denominator` is zero, or if the resulting fraction
+	/// doesn't fit back into `i16`.
+	#[must_use]
+	pub fn approximate(value: f64, max_denominator: u16) -> Self {
+		assert!(max_denominator > 0, "max_denominator must not be zero");
+
+		let sign = if value.is_sign_negative() { -1i64 } else { 1 };
+		let target = value.abs();
+		let max_denominator = i64::from(max_denominator);
+
+		let (mut p0, mut q0, mut p1, mut q1) = (0i64, 1i64, 1i64, 0i64);
+		let mut x = target;
+
+		loop {
+			// On the first term q1 is still 0, so a*q1 is 0 no matter how
+			// large a is: the denominator bound can't be violated yet, and
+			// clamping a here would silently truncate the integer part of
+			// `value` instead of bounding anything.
+			let a = if q1 == 0 {
+				x.floor() as i64
+			} else {
+				x.floor().clamp(0.0, (max_denominator + 1) as f64) as i64
+			};
+			let q2 = q0 + a * q1;
+			if q2 > max_denominator {
+				break;
+			}
+
+			let p2 = a * p1 + p0;
+			(p0, q0) = (p1, q1);
+			(p1, q1) = (p2, q2);
+
+			let fract = x - x.floor();
+			if fract <= f64::EPSILON {
+				return Self::from_signed_parts(sign, p1, q1);
+			}
+			x = 1.0 / fract;
+		}
+
+		// p1/q1 is the best convergent within the bound; the semiconvergent
+		// between it and the previous convergent p0/q0 may land closer.
+		let k = if q1 == 0 { 0 } else { (max_denominator - q0) / q1 };
+		let semiconvergent = (p0 + k * p1, q0 + k * q1);
+
+		#[allow(clippy::cast_precision_loss)]
+		let convergent_error = (p1 as f64 / q1 as f64 - target).abs();
+		#[allow(clippy::cast_precision_loss)]
+		let semiconvergent_error =
+			(semiconvergent.0 as f64 / semiconvergent.1 as f64 - target).abs();
+
+		let (numerator, denominator) = if convergent_error <= semiconvergent_error {
+			(p1, q1)
+		} else {
+			semiconvergent
+		};
+
+		Self::from_signed_parts(sign, numerator, denominator)
+	}
+
+	/// Returns the closest fractions representable with a denominator no
+	/// larger than `max_denominator`, bracketing `self` from below and
+	/// above.
+	///
+	/// If `self` is already representable within the budget, both halves
+	/// of the returned pair equal `self`. Otherwise this performs the same
+	/// continued-fraction search as [`Self::approximate`], but on the
+	/// exact numerator/denominator of `self` rather than a floating-point
+	/// target, so the bound is exact rather than `f64`-limited.
+	///
+	/// # Panics
+	///
+	/// Panics if `max_denominator` is zero.
+	#[must_use]
+	pub fn bound(self, max_denominator: u16) -> (Self, Self) {
+		assert!(max_denominator > 0, "max_denominator must not be zero");
+
+		if self.denominator.unsigned_abs() <= max_denominator {
+			return (self, self);
+		}
+
+		let sign = i64::from(self.numerator.signum());
+		let target_numerator = i64::from(self.numerator.unsigned_abs());
+		let target_denominator = i64::from(self.denominator);
+		let max_denominator = i64::from(max_denominator);
+
+		let (mut p0, mut q0, mut p1, mut q1) = (0i64, 1i64, 1i64, 0i64);
+		let (mut n, mut d) = (target_numerator, target_denominator);
+
+		loop {
+			let a = n / d;
+			let q2 = q0 + a * q1;
+			if q2 > max_denominator {
+				break;
+			}
+
+			let p2 = a * p1 + p0;
+			(p0, q0) = (p1, q1);
+			(p1, q1) = (p2, q2);
+
+			let r = n % d;
+			if r == 0 {
+				break;
+			}
+			(n, d) = (d, r);
+		}
+
+		let k = if q1 == 0 { 0 } else { (max_denominator - q0) / q1 };
+		let semiconvergent = (p0 + k * p1, q0 + k * q1);
+
+		let convergent_cmp = (p1 * target_denominator).cmp(&(target_numerator * q1));
+		let (below, above) = match convergent_cmp {
+			core::cmp::Ordering::Less => ((p1, q1), semiconvergent),
+			_ => (semiconvergent, (p1, q1)),
+		};
+
+		if sign < 0 {
+			(
+				Self::from_signed_parts(sign, above.0, above.1),
+				Self::from_signed_parts(sign, below.0, below.1),
+			)
+		} else {
+			(
+				Self::from_signed_parts(sign, below.0, below.1),
+				Self::from_signed_parts(sign, above.0, above.1),
+			)
+		}
+	}
+
+	/// Recovers the exact dyadic fraction backing `value`, if its reduced
+	/// form fits in `i16`.
+	///
+	/// Every finite `f64` is exactly `mantissa * 2^exponent`, so this
+	/// decodes the value's bits directly rather than approximating.
+	/// Returns `None` when `value` is not finite, or when the exact
+	/// fraction's numerator or denominator would overflow; callers that
+	/// want a result regardless should fall back to
+	/// [`Self::approximate`].
+	#[must_use]
+	pub fn from_f64_exact(value: f64) -> Option<Self> {
+		if value == 0.0 {
+			return Some(Self::zero());
+		}
+		if !value.is_finite() {
+			return None;
+		}
+
+		let bits = value.to_bits();
+		let sign: i128 = if bits >> 63 == 0 { 1 } else { -1 };
+		let biased_exponent = i32::try_from((bits >> 52) & 0x7ff).unwrap();
+		let mantissa = if biased_exponent == 0 {
+			(bits & 0xf_ffff_ffff_ffff) << 1
+		} else {
+			(bits & 0xf_ffff_ffff_ffff) | 0x10_0000_0000_0000
+		};
+		let exponent = biased_exponent - 1075;
+
+		// strip trailing zero bits so the denominator is as small as possible
+		let shift = mantissa.trailing_zeros();
+		let mantissa = mantissa >> shift;
+		let exponent = exponent + i32::try_from(shift).unwrap();
+
+		// anything beyond this can't possibly fit a 16-bit numerator/denominator
+		if exponent.unsigned_abs() > 20 {
+			return None;
+		}
+
+		let (numerator, denominator): (i128, i128) = if exponent >= 0 {
+			(i128::from(mantissa) << exponent, 1)
+		} else {
+			(i128::from(mantissa), 1i128 << -exponent)
+		};
+
+		if denominator > i128::from(i16::MAX) {
+			return None;
+		}
+
+		let numerator = i16::try_from(sign * numerator).ok()?;
+		let denominator = i16::try_from(denominator).ok()?;
 
 		Some(Self::new(numerator, denominator))
 	}
@@ -126,7 +916,12 @@ impl Fraction32 {
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum ParseFractionError {
 	BadInteger(ParseIntError),
+	/// The digits after a decimal point were missing or not all ASCII digits.
+	BadDecimal,
+	/// The denominator was zero or negative.
 	ZeroDenominator,
+	/// The parsed value doesn't fit back into `i16`.
+	Overflow,
 }
 
 impl From<ParseIntError> for ParseFractionError {
@@ -135,14 +930,97 @@ impl From<ParseIntError> for ParseFractionError {
 	}
 }
 
+/// Error returned when converting a non-finite `f64` into a `Fraction32`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TryFromF64Error;
+
+impl Fraction32 {
+	/// Parses a mixed number like `"1 1/2"` or `"-1 1/2"`: a whole part and
+	/// a proper fraction, combined with the whole part's sign applied to
+	/// the sum rather than to the whole part alone.
+	fn parse_mixed(whole: &str, frac: &str) -> Result<Self, ParseFractionError> {
+		let negative = whole.starts_with('-');
+		let whole: i16 = whole
+			.parse::<i16>()?
+			.unsigned_abs()
+			.try_into()
+			.map_err(|_| ParseFractionError::Overflow)?;
+		let frac: Self = frac.parse()?;
+
+		let magnitude = Self::whole(whole)
+			.checked_add(frac)
+			.ok_or(ParseFractionError::Overflow)?;
+
+		if negative {
+			magnitude
+				.checked_mul(Self::NEG_ONE)
+				.ok_or(ParseFractionError::Overflow)
+		} else {
+			Ok(magnitude)
+		}
+	}
+
+	/// Parses a decimal literal like `"1.25"` or `"-0.5"` by scaling the
+	/// digits after the point by the power of ten they imply, then
+	/// reducing.
+	fn parse_decimal(s: &str) -> Result<Self, ParseFractionError> {
+		let negative = s.starts_with('-');
+		let unsigned = s.strip_prefix(['+', '-']).unwrap_or(s);
+		let (int_part, frac_part) = unsigned.split_once('.').unwrap();
+
+		if frac_part.is_empty() || !frac_part.bytes().all(|b| b.is_ascii_digit()) {
+			return Err(ParseFractionError::BadDecimal);
+		}
+
+		let int_value: i64 = if int_part.is_empty() { 0 } else { int_part.parse()? };
+		let frac_value: i64 = frac_part.parse()?;
+		let scale = 10i64
+			.checked_pow(u32::try_from(frac_part.len()).unwrap())
+			.ok_or(ParseFractionError::Overflow)?;
+
+		let numerator = int_value
+			.checked_mul(scale)
+			.and_then(|n| n.checked_add(frac_value))
+			.ok_or(ParseFractionError::Overflow)?;
+		let numerator = if negative { -numerator } else { numerator };
+
+		if numerator == 0 {
+			return Ok(Self::zero());
+		}
+
+		// Reduce in a wide type before narrowing: a literal like "0.12500"
+		// has a large power-of-ten scale, but the reduced fraction (1/8)
+		// fits comfortably in i16.
+		let gcd = gcd_u64(numerator.unsigned_abs(), scale.unsigned_abs());
+		let numerator = numerator / i64::try_from(gcd).map_err(|_| ParseFractionError::Overflow)?;
+		let denominator = scale / i64::try_from(gcd).map_err(|_| ParseFractionError::Overflow)?;
+
+		let numerator = i16::try_from(numerator).map_err(|_| ParseFractionError::Overflow)?;
+		let denominator = i16::try_from(denominator).map_err(|_| ParseFractionError::Overflow)?;
+
+		Ok(Self::new(numerator, denominator))
+	}
+}
+
 impl FromStr for Fraction32 {
 	type Err = ParseFractionError;
 	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let s = s.trim();
+
+		if let Some((whole, frac)) = s.split_once(' ') {
+			return Self::parse_mixed(whole, frac);
+		}
+
+		if s.contains('.') {
+			return Self::parse_decimal(s);
+		}
+
 		if let Some((numerator, denominator)) = s.split_once('/') {
 			let numerator = numerator.parse()?;
-			let denominator = denominator.parse()?;
-			let denominator =
-				NonZeroU16::new(denominator).ok_or(ParseFractionError::ZeroDenominator)?;
+			let denominator: i16 = denominator.parse()?;
+			if denominator <= 0 {
+				return Err(ParseFractionError::ZeroDenominator);
+			}
 
 			Ok(Self::new(numerator, denominator))
 		} else {
@@ -151,93 +1029,385 @@ impl FromStr for Fraction32 {
 	}
 }
 
-impl PartialOrd<Self> for Fraction32 {
+impl<T: Int> PartialOrd<Self> for Fraction<T> {
 	fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
-		let lcm = lcm(self.denominator.get(), other.denominator.get());
-		let self_scale: i16 = (lcm / self.denominator).try_into().ok()?;
-		let other_scale: i16 = (lcm / other.denominator).try_into().ok()?;
-
-		(self.numerator * self_scale).partial_cmp(&(other.numerator * other_scale))
+		Some(self.cmp(other))
 	}
 }
 
-impl Ord for Fraction32 {
+impl<T: Int> Ord for Fraction<T> {
 	fn cmp(&self, other: &Self) -> core::cmp::Ordering {
-		self.partial_cmp(other).unwrap()
+		T::cross_compare(
+			&self.numerator,
+			&self.denominator,
+			&other.numerator,
+			&other.denominator,
+		)
 	}
 }
 
-impl Display for Fraction32 {
+impl<T: Int> Display for Fraction<T> {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 		write!(f, "{}/{}", self.numerator, self.denominator)
 	}
 }
 
-impl From<i16> for Fraction32 {
-	fn from(v: i16) -> Self {
+impl<T: Int> From<T> for Fraction<T> {
+	fn from(v: T) -> Self {
 		Self::whole(v)
 	}
 }
 
-impl Add<Self> for Fraction32 {
+impl From<Fraction32> for f64 {
+	fn from(v: Fraction32) -> Self {
+		Self::from(v.numerator) / Self::from(v.denominator)
+	}
+}
+
+impl TryFrom<f64> for Fraction32 {
+	type Error = TryFromF64Error;
+
+	/// Recovers the exact dyadic fraction when `value` fits one, falling
+	/// back to the closest approximation with a denominator no larger
+	/// than `i16::MAX`.
+	fn try_from(value: f64) -> Result<Self, Self::Error> {
+		if !value.is_finite() {
+			return Err(TryFromF64Error);
+		}
+
+		Ok(Self::from_f64_exact(value)
+			.unwrap_or_else(|| Self::approximate(value, i16::MAX.unsigned_abs())))
+	}
+}
+
+impl<T: Int> Add<Self> for Fraction<T> {
 	type Output = Self;
 
 	fn add(self, rhs: Self) -> Self::Output {
-		let denominator = lcm(self.denominator.get(), rhs.denominator.get());
-		let self_scale: i16 = (denominator / self.denominator).try_into().ok().unwrap();
-		let other_scale: i16 = (denominator / rhs.denominator).try_into().ok().unwrap();
-		let numerator = self.numerator * self_scale + rhs.numerator * other_scale;
-		Self::new(numerator, NonZeroU16::new(denominator).unwrap())
+		let denominator = lcm(&self.denominator, &rhs.denominator)
+			.expect("combining two valid fractions' denominators should not overflow T");
+		let self_scale = denominator
+			.checked_div(&self.denominator)
+			.expect("lcm is a multiple of self.denominator");
+		let other_scale = denominator
+			.checked_div(&rhs.denominator)
+			.expect("lcm is a multiple of rhs.denominator");
+
+		let numerator = self
+			.numerator
+			.checked_mul(&self_scale)
+			.and_then(|scaled_lhs| {
+				rhs.numerator
+					.checked_mul(&other_scale)
+					.and_then(|scaled_rhs| scaled_lhs.checked_add(&scaled_rhs))
+			})
+			.expect("adding two valid fractions should not overflow T");
+
+		Self::new(numerator, denominator)
 	}
 }
 
-impl AddAssign<Self> for Fraction32 {
+impl<T: Int> AddAssign<Self> for Fraction<T> {
 	fn add_assign(&mut self, rhs: Self) {
-		*self = *self + rhs;
+		*self = self.clone() + rhs;
 	}
 }
 
-impl Sub<Self> for Fraction32 {
+impl<T: Int> Sub<Self> for Fraction<T> {
 	type Output = Self;
 
 	fn sub(self, rhs: Self) -> Self::Output {
-		self.add(rhs.mul(Self::NEG_ONE))
+		let negated = Self {
+			numerator: rhs
+				.numerator
+				.checked_neg()
+				.expect("negating a valid fraction's numerator should not overflow T"),
+			denominator: rhs.denominator,
+		};
+
+		self.add(negated)
 	}
 }
 
-impl SubAssign<Self> for Fraction32 {
+impl<T: Int> SubAssign<Self> for Fraction<T> {
 	fn sub_assign(&mut self, rhs: Self) {
-		*self = *self - rhs;
+		*self = self.clone() - rhs;
 	}
 }
 
-impl Mul<Self> for Fraction32 {
+impl<T: Int> Mul<Self> for Fraction<T> {
 	type Output = Self;
 
 	fn mul(self, rhs: Self) -> Self::Output {
-		let numerator = self.numerator * rhs.numerator;
-		let denominator = self.denominator.checked_mul(rhs.denominator).unwrap();
+		let numerator = self
+			.numerator
+			.checked_mul(&rhs.numerator)
+			.expect("multiplying two valid fractions' numerators should not overflow T");
+		let denominator = self
+			.denominator
+			.checked_mul(&rhs.denominator)
+			.expect("multiplying two valid fractions' denominators should not overflow T");
 
 		Self::new(numerator, denominator)
 	}
 }
 
-impl MulAssign<Self> for Fraction32 {
+impl<T: Int> MulAssign<Self> for Fraction<T> {
 	fn mul_assign(&mut self, rhs: Self) {
-		*self = *self * rhs;
+		*self = self.clone() * rhs;
 	}
 }
 
-impl Div<Self> for Fraction32 {
+impl<T: Int> Div<Self> for Fraction<T> {
 	type Output = Self;
 
 	fn div(self, rhs: Self) -> Self::Output {
-		self.mul(rhs.reciprocal().unwrap())
+		self.mul(
+			rhs.reciprocal()
+				.expect("dividing by a zero fraction is not supported"),
+		)
 	}
 }
 
-impl DivAssign<Self> for Fraction32 {
+impl<T: Int> DivAssign<Self> for Fraction<T> {
 	fn div_assign(&mut self, rhs: Self) {
-		*self = *self / rhs;
+		*self = self.clone() / rhs;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn checked_add_combines_across_denominators() {
+		assert_eq!(
+			Fraction32::new(1, 2).checked_add(Fraction32::new(1, 3)),
+			Some(Fraction32::new(5, 6))
+		);
+	}
+
+	#[test]
+	fn checked_add_detects_overflow() {
+		let huge = Fraction32::new(i16::MAX, 1);
+		assert_eq!(huge.checked_add(huge), None);
+	}
+
+	#[test]
+	fn checked_sub_combines_across_denominators() {
+		assert_eq!(
+			Fraction32::new(1, 2).checked_sub(Fraction32::new(1, 3)),
+			Some(Fraction32::new(1, 6))
+		);
+	}
+
+	#[test]
+	fn checked_mul_reduces_the_product() {
+		assert_eq!(
+			Fraction32::new(2, 3).checked_mul(Fraction32::new(3, 4)),
+			Some(Fraction32::new(1, 2))
+		);
+	}
+
+	#[test]
+	fn checked_mul_detects_overflow() {
+		let huge = Fraction32::new(i16::MAX, 1);
+		assert_eq!(huge.checked_mul(huge), None);
+	}
+
+	#[test]
+	fn checked_div_is_multiplication_by_the_reciprocal() {
+		assert_eq!(
+			Fraction32::new(1, 2).checked_div(Fraction32::new(1, 3)),
+			Some(Fraction32::new(3, 2))
+		);
+	}
+
+	#[test]
+	fn checked_div_by_zero_is_none() {
+		assert_eq!(
+			Fraction32::new(1, 2).checked_div(Fraction32::zero()),
+			None
+		);
+	}
+
+	#[test]
+	fn checked_reciprocal_flips_numerator_and_denominator() {
+		assert_eq!(
+			Fraction32::new(-2, 3).checked_reciprocal(),
+			Some(Fraction32::new(-3, 2))
+		);
+	}
+
+	#[test]
+	fn checked_reciprocal_of_zero_is_none() {
+		assert_eq!(Fraction32::zero().checked_reciprocal(), None);
+	}
+
+	#[test]
+	fn approximate_exact_integer() {
+		assert_eq!(Fraction32::approximate(100.0, 10), Fraction32::new(100, 1));
+	}
+
+	#[test]
+	fn approximate_picks_best_semiconvergent() {
+		assert_eq!(
+			Fraction32::approximate(123.456, 50),
+			Fraction32::new(5679, 46)
+		);
+	}
+
+	#[test]
+	fn approximate_exact_within_budget_is_unchanged() {
+		assert_eq!(Fraction32::approximate(0.5, 10), Fraction32::new(1, 2));
+	}
+
+	#[test]
+	fn approximate_preserves_sign() {
+		assert_eq!(
+			Fraction32::approximate(-100.0, 10),
+			Fraction32::new(-100, 1)
+		);
+	}
+
+	#[test]
+	fn bound_brackets_unrepresentable_fraction() {
+		let value = Fraction32::new(5679, 46);
+		let (below, above) = value.bound(10);
+
+		assert!(below <= value);
+		assert!(value <= above);
+		assert!(below.denominator() <= 10);
+		assert!(above.denominator() <= 10);
+	}
+
+	#[test]
+	fn bound_is_a_no_op_within_budget() {
+		let value = Fraction32::new(1, 2);
+		assert_eq!(value.bound(10), (value, value));
+	}
+
+	#[test]
+	fn from_f64_exact_recovers_dyadic_fractions() {
+		assert_eq!(Fraction32::from_f64_exact(0.5), Some(Fraction32::new(1, 2)));
+		assert_eq!(
+			Fraction32::from_f64_exact(1.25),
+			Some(Fraction32::new(5, 4))
+		);
+		assert_eq!(Fraction32::from_f64_exact(-2.0), Some(Fraction32::whole(-2)));
+		assert_eq!(Fraction32::from_f64_exact(0.0), Some(Fraction32::zero()));
+	}
+
+	#[test]
+	fn from_f64_exact_rejects_non_finite_and_overflow() {
+		assert_eq!(Fraction32::from_f64_exact(f64::NAN), None);
+		assert_eq!(Fraction32::from_f64_exact(f64::INFINITY), None);
+		// the f64 nearest 1/3 is a dyadic fraction whose denominator (2^54)
+		// is far too large for i16
+		assert_eq!(Fraction32::from_f64_exact(1.0 / 3.0), None);
+	}
+
+	#[test]
+	fn f64_from_fraction32_round_trips() {
+		let value = Fraction32::new(5, 4);
+		assert!((f64::from(value) - 1.25).abs() < f64::EPSILON);
+	}
+
+	#[test]
+	fn try_from_f64_falls_back_to_approximation() {
+		let value = Fraction32::try_from(1.0 / 3.0).unwrap();
+		assert!((f64::from(value) - 1.0 / 3.0).abs() < 1e-9);
+	}
+
+	#[test]
+	fn try_from_f64_rejects_non_finite() {
+		assert_eq!(Fraction32::try_from(f64::NAN), Err(TryFromF64Error));
+		assert_eq!(Fraction32::try_from(f64::INFINITY), Err(TryFromF64Error));
+	}
+
+	#[test]
+	fn parse_decimal_reduces_before_narrowing() {
+		assert_eq!("0.12500".parse(), Ok(Fraction32::new(1, 8)));
+		assert_eq!("0.00016".parse(), Ok(Fraction32::new(1, 6250)));
+		assert_eq!("-0.5".parse(), Ok(Fraction32::new(-1, 2)));
+	}
+
+	#[test]
+	fn parse_decimal_rejects_bad_digits() {
+		assert_eq!(
+			"1.".parse::<Fraction32>(),
+			Err(ParseFractionError::BadDecimal)
+		);
+		assert_eq!(
+			"1.2x".parse::<Fraction32>(),
+			Err(ParseFractionError::BadDecimal)
+		);
+	}
+
+	#[test]
+	fn parse_mixed_number() {
+		assert_eq!("1 1/2".parse(), Ok(Fraction32::new(3, 2)));
+		assert_eq!("-1 1/2".parse(), Ok(Fraction32::new(-3, 2)));
+	}
+
+	#[test]
+	fn parse_fraction_and_whole() {
+		assert_eq!("3/4".parse(), Ok(Fraction32::new(3, 4)));
+		assert_eq!("5".parse(), Ok(Fraction32::whole(5)));
+	}
+
+	#[test]
+	fn parse_rejects_non_positive_denominator() {
+		assert_eq!(
+			"1/0".parse::<Fraction32>(),
+			Err(ParseFractionError::ZeroDenominator)
+		);
+		assert_eq!(
+			"1/-2".parse::<Fraction32>(),
+			Err(ParseFractionError::ZeroDenominator)
+		);
+	}
+
+	#[test]
+	fn bigint_arithmetic_never_overflows_past_i128() {
+		// i128::MAX + i128::MAX would overflow a fixed-width backend, but
+		// BigInt just grows another limb.
+		let max = BigInt::new(i128::MAX);
+		let sum = max.checked_add(&max).unwrap();
+
+		assert_eq!(sum.to_string(), "340282366920938463463374607431768211454");
+	}
+
+	#[test]
+	fn bigint_displays_large_magnitudes() {
+		assert_eq!(BigInt::new(0).to_string(), "0");
+		assert_eq!(BigInt::new(-12345).to_string(), "-12345");
+		assert_eq!(BigInt::new(i128::MIN).to_string(), i128::MIN.to_string());
+	}
+
+	#[test]
+	fn bigint_division_and_remainder() {
+		let a = BigInt::new(-17);
+		let b = BigInt::new(5);
+
+		assert_eq!(a.checked_div(&b), Some(BigInt::new(-3)));
+		assert_eq!(a.checked_rem(&b), Some(BigInt::new(-2)));
+		assert_eq!(a.checked_div(&BigInt::new(0)), None);
+	}
+
+	#[test]
+	fn bigint_ordering() {
+		assert!(BigInt::new(-5) < BigInt::new(-1));
+		assert!(BigInt::new(-1) < BigInt::new(0));
+		assert!(BigInt::new(0) < BigInt::new(1));
+		assert!(BigInt::new(i128::MAX) < BigInt::new(i128::MAX).checked_add(&BigInt::new(1)).unwrap());
+	}
+
+	#[test]
+	fn fraction_big_survives_beyond_i16_max() {
+		let huge = FractionBig::whole(BigInt::new(i128::from(i16::MAX) + 1));
+		let doubled = huge.clone() + huge.clone();
+
+		assert_eq!(doubled.numerator(), BigInt::new(2 * (i128::from(i16::MAX) + 1)));
 	}
 }